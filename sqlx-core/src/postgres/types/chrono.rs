@@ -1,12 +1,117 @@
 use crate::{Decode, Postgres, Encode, HasSqlType, HasTypeMetadata};
-use chrono::{NaiveTime, Timelike, NaiveDate, TimeZone, DateTime, NaiveDateTime, Utc, Local, Duration, Date};
+use chrono::{NaiveTime, Timelike, NaiveDate, TimeZone, DateTime, NaiveDateTime, Utc, Local, FixedOffset, Duration, Date};
 use crate::postgres::types::{PostgresTypeMetadata, PostgresTypeFormat};
 use crate::encode::IsNull;
 
+use std::cell::Cell;
 use std::convert::TryInto;
 
 use std::mem::size_of;
 
+thread_local! {
+    /// Whether the connected server transmits `timestamp`/`timestamptz`/`time`/
+    /// `interval` values as `i64` microseconds (the default) or, for servers built
+    /// with `--disable-integer-datetimes`, as `f64` seconds since the Postgres epoch.
+    ///
+    /// The server reports this as the `integer_datetimes` parameter in a
+    /// `ParameterStatus` message at connection startup.
+    ///
+    /// FIXME: this is `thread_local`, not per-connection, and that is unsound for an
+    /// async driver: a connection's future is not pinned to the OS thread that ran its
+    /// handshake, so under any multi-threaded executor it can resume decoding/encoding
+    /// on a different thread than the one [`set_integer_datetimes`] was called on for
+    /// it, silently reading whatever flag another connection (or the `true` default)
+    /// last left on that thread. The correct fix is to carry this flag as state on the
+    /// connection itself and thread it into the `Decode`/`Encode` call path from
+    /// wherever the connection parses its startup `ParameterStatus` messages, but
+    /// `Decode`/`Encode` as called here take no connection context (`fn decode(raw:
+    /// Option<&[u8]>) -> Self`), and no connection/startup code exists in this module
+    /// to carry it — both are out of scope for `postgres/types`. Until that lands,
+    /// `set_integer_datetimes` has no caller anywhere in this crate: the float-format
+    /// path below can never leave its `true` default, so servers with
+    /// `integer_datetimes = off` are still silently misread.
+    static INTEGER_DATETIMES: Cell<bool> = Cell::new(true);
+}
+
+pub(crate) fn set_integer_datetimes(enabled: bool) {
+    INTEGER_DATETIMES.with(|flag| flag.set(enabled));
+}
+
+fn integer_datetimes() -> bool {
+    INTEGER_DATETIMES.with(|flag| flag.get())
+}
+
+fn micros_to_float_seconds(micros: i64) -> f64 {
+    micros as f64 / 1_000_000.0
+}
+
+fn float_seconds_to_micros(secs: f64) -> i64 {
+    (secs * 1_000_000.0).round() as i64
+}
+
+pub(crate) fn decode_micros(raw: Option<&[u8]>) -> i64 {
+    if integer_datetimes() {
+        decode(raw)
+    } else {
+        float_seconds_to_micros(decode(raw))
+    }
+}
+
+pub(crate) fn encode_micros(micros: i64, buf: &mut Vec<u8>) {
+    if integer_datetimes() {
+        Encode::<Postgres>::encode(&micros, buf);
+    } else {
+        Encode::<Postgres>::encode(&micros_to_float_seconds(micros), buf);
+    }
+}
+
+/// The result of decoding a Postgres microsecond-based temporal value that may hold
+/// the `infinity`/`-infinity` sentinel, detected *before* any int/float conversion
+/// that would otherwise turn the sentinel into a large-but-finite value.
+enum MicrosOrInfinity {
+    Micros(i64),
+    PosInfinity,
+    NegInfinity,
+}
+
+fn decode_micros_or_infinity(raw: Option<&[u8]>) -> MicrosOrInfinity {
+    if integer_datetimes() {
+        match decode(raw) {
+            i64::MAX => MicrosOrInfinity::PosInfinity,
+            i64::MIN => MicrosOrInfinity::NegInfinity,
+            micros => MicrosOrInfinity::Micros(micros),
+        }
+    } else {
+        let secs: f64 = decode(raw);
+
+        if secs == f64::MAX {
+            MicrosOrInfinity::PosInfinity
+        } else if secs == f64::MIN {
+            MicrosOrInfinity::NegInfinity
+        } else {
+            MicrosOrInfinity::Micros(float_seconds_to_micros(secs))
+        }
+    }
+}
+
+fn encode_micros_or_infinity(value: MicrosOrInfinity, buf: &mut Vec<u8>) {
+    match value {
+        MicrosOrInfinity::Micros(micros) => encode_micros(micros, buf),
+        MicrosOrInfinity::PosInfinity if integer_datetimes() => {
+            Encode::<Postgres>::encode(&i64::MAX, buf);
+        }
+        MicrosOrInfinity::PosInfinity => {
+            Encode::<Postgres>::encode(&f64::MAX, buf);
+        }
+        MicrosOrInfinity::NegInfinity if integer_datetimes() => {
+            Encode::<Postgres>::encode(&i64::MIN, buf);
+        }
+        MicrosOrInfinity::NegInfinity => {
+            Encode::<Postgres>::encode(&f64::MIN, buf);
+        }
+    }
+}
+
 postgres_metadata!(
     // time
     NaiveTime: PostgresTypeMetadata {
@@ -33,6 +138,12 @@ postgres_metadata!(
         array_oid: 1185
     },
     // Date<Tz: TimeZone> is not covered as Postgres does not have a "date with timezone" type
+    // timetz
+    PgTimeTz: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1266,
+        array_oid: 1270
+    },
 );
 
 fn decode<T: Decode<Postgres>>(raw: Option<&[u8]>) -> T {
@@ -41,7 +152,7 @@ fn decode<T: Decode<Postgres>>(raw: Option<&[u8]>) -> T {
 
 impl Decode<Postgres> for NaiveTime {
     fn decode(raw: Option<&[u8]>) -> Self {
-        let micros: i64 = decode(raw);
+        let micros = decode_micros(raw);
         NaiveTime::from_hms(0, 0, 0) + Duration::microseconds(micros)
     }
 }
@@ -52,7 +163,8 @@ impl Encode<Postgres> for NaiveTime {
             .num_microseconds()
             .expect("shouldn't overflow");
 
-        Encode::<Postgres>::encode(&micros, buf)
+        encode_micros(micros, buf);
+        IsNull::No
     }
 
     fn size_hint(&self) -> usize {
@@ -84,7 +196,7 @@ impl Encode<Postgres> for NaiveDate {
 
 impl Decode<Postgres> for NaiveDateTime {
     fn decode(raw: Option<&[u8]>) -> Self {
-        let micros: i64 = decode(raw);
+        let micros = decode_micros(raw);
         postgres_epoch().naive_utc()
             .checked_add_signed(Duration::microseconds(micros))
             .unwrap_or_else(|| panic!("Postgres timestamp out of range for NaiveDateTime: {:?}", micros))
@@ -97,7 +209,8 @@ impl Encode<Postgres> for NaiveDateTime {
             .num_microseconds()
             .unwrap_or_else(|| panic!("NaiveDateTime out of range for Postgres: {:?}", self));
 
-        Encode::<Postgres>::encode(&micros, buf)
+        encode_micros(micros, buf);
+        IsNull::No
     }
 
     fn size_hint(&self) -> usize {
@@ -119,6 +232,59 @@ impl Decode<Postgres> for DateTime<Local> {
     }
 }
 
+impl Decode<Postgres> for DateTime<FixedOffset> {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        // `timestamptz` is always transmitted in UTC; the offset here is fixed at zero
+        // purely so callers who have standardized on `DateTime<FixedOffset>` don't have
+        // to route through `Utc` or `Local` to get a value back.
+        let date_time = <NaiveDateTime as Decode<Postgres>>::decode(raw);
+        FixedOffset::east(0).from_utc_datetime(&date_time)
+    }
+}
+
+/// A Postgres `timetz` value: a time of day paired with a UTC offset.
+///
+/// There is no `chrono` type that pairs a bare time with an offset (`DateTime` always
+/// carries a date), so this wraps `NaiveTime` and `FixedOffset` directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgTimeTz {
+    pub time: NaiveTime,
+    pub offset: FixedOffset,
+}
+
+impl Decode<Postgres> for PgTimeTz {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let buf = raw.expect("Postgres timetz cannot be decoded from NULL");
+
+        let micros = decode_micros(Some(&buf[0..8]));
+        let offset_secs = i32::from_be_bytes(buf[8..12].try_into().unwrap());
+
+        let time = NaiveTime::from_hms(0, 0, 0) + Duration::microseconds(micros);
+        // Postgres stores the offset west-positive; chrono's FixedOffset is east-positive.
+        let offset = FixedOffset::east(-offset_secs);
+
+        PgTimeTz { time, offset }
+    }
+}
+
+impl Encode<Postgres> for PgTimeTz {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let micros = (self.time - NaiveTime::from_hms(0, 0, 0))
+            .num_microseconds()
+            .expect("shouldn't overflow");
+        let offset_secs = -self.offset.local_minus_utc();
+
+        encode_micros(micros, buf);
+        buf.extend(&offset_secs.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>() + size_of::<i32>()
+    }
+}
+
 impl<Tz: TimeZone> Encode<Postgres> for DateTime<Tz> where Tz::Offset: Copy {
     fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
         Encode::<Postgres>::encode(&self.naive_utc(), buf)
@@ -133,6 +299,201 @@ fn postgres_epoch() -> DateTime<Utc> {
     Utc.ymd(2000, 1, 1).and_hms(0, 0, 0)
 }
 
+/// A Postgres `timestamp` or `timestamptz` value, with support for the `infinity` and
+/// `-infinity` sentinel values that Postgres accepts but `chrono` cannot represent.
+///
+/// On the wire these are encoded as `i64::MAX` and `i64::MIN` microseconds respectively;
+/// decoding a finite value otherwise behaves exactly like `T`'s own `Decode` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgTimestamp<T> {
+    PosInfinity,
+    NegInfinity,
+    Value(T),
+}
+
+/// A Postgres `date` value, with support for the `infinity` and `-infinity` sentinel
+/// values that Postgres accepts but `chrono::NaiveDate` cannot represent.
+///
+/// On the wire these are encoded as `i32::MAX` and `i32::MIN` days respectively; decoding
+/// a finite value otherwise behaves exactly like `T`'s own `Decode` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgDate<T> {
+    PosInfinity,
+    NegInfinity,
+    Value(T),
+}
+
+postgres_metadata!(
+    // timestamp
+    PgTimestamp<NaiveDateTime>: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1114,
+        array_oid: 1115
+    },
+    // timestamptz
+    { Tz: TimeZone } PgTimestamp<DateTime<Tz>>: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1184,
+        array_oid: 1185
+    },
+    // date
+    PgDate<NaiveDate>: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1082,
+        array_oid: 1182
+    },
+);
+
+impl Decode<Postgres> for PgTimestamp<NaiveDateTime> {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        match decode_micros_or_infinity(raw) {
+            MicrosOrInfinity::PosInfinity => PgTimestamp::PosInfinity,
+            MicrosOrInfinity::NegInfinity => PgTimestamp::NegInfinity,
+            MicrosOrInfinity::Micros(micros) => PgTimestamp::Value(
+                postgres_epoch().naive_utc()
+                    .checked_add_signed(Duration::microseconds(micros))
+                    .unwrap_or_else(|| panic!("Postgres timestamp out of range for NaiveDateTime: {:?}", micros)),
+            ),
+        }
+    }
+}
+
+impl Encode<Postgres> for PgTimestamp<NaiveDateTime> {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let value = match self {
+            PgTimestamp::PosInfinity => MicrosOrInfinity::PosInfinity,
+            PgTimestamp::NegInfinity => MicrosOrInfinity::NegInfinity,
+            PgTimestamp::Value(value) => {
+                return Encode::<Postgres>::encode(value, buf);
+            }
+        };
+
+        encode_micros_or_infinity(value, buf);
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>()
+    }
+}
+
+impl Decode<Postgres> for PgTimestamp<DateTime<Utc>> {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        match <PgTimestamp<NaiveDateTime> as Decode<Postgres>>::decode(raw) {
+            PgTimestamp::PosInfinity => PgTimestamp::PosInfinity,
+            PgTimestamp::NegInfinity => PgTimestamp::NegInfinity,
+            PgTimestamp::Value(date_time) => PgTimestamp::Value(DateTime::from_utc(date_time, Utc)),
+        }
+    }
+}
+
+impl Decode<Postgres> for PgTimestamp<DateTime<Local>> {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        match <PgTimestamp<NaiveDateTime> as Decode<Postgres>>::decode(raw) {
+            PgTimestamp::PosInfinity => PgTimestamp::PosInfinity,
+            PgTimestamp::NegInfinity => PgTimestamp::NegInfinity,
+            PgTimestamp::Value(date_time) => PgTimestamp::Value(Local.from_utc_datetime(&date_time)),
+        }
+    }
+}
+
+impl<Tz: TimeZone> Encode<Postgres> for PgTimestamp<DateTime<Tz>> where Tz::Offset: Copy {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let value = match self {
+            PgTimestamp::PosInfinity => MicrosOrInfinity::PosInfinity,
+            PgTimestamp::NegInfinity => MicrosOrInfinity::NegInfinity,
+            PgTimestamp::Value(value) => {
+                return Encode::<Postgres>::encode(&value.naive_utc(), buf);
+            }
+        };
+
+        encode_micros_or_infinity(value, buf);
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>()
+    }
+}
+
+impl Decode<Postgres> for PgDate<NaiveDate> {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let days: i32 = decode(raw);
+
+        match days {
+            i32::MAX => PgDate::PosInfinity,
+            i32::MIN => PgDate::NegInfinity,
+            days => PgDate::Value(NaiveDate::from_ymd(2000, 1, 1) + Duration::days(days as i64)),
+        }
+    }
+}
+
+impl Encode<Postgres> for PgDate<NaiveDate> {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let days = match self {
+            PgDate::PosInfinity => i32::MAX,
+            PgDate::NegInfinity => i32::MIN,
+            PgDate::Value(value) => {
+                return Encode::<Postgres>::encode(value, buf);
+            }
+        };
+
+        Encode::<Postgres>::encode(&days, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i32>()
+    }
+}
+
+#[test]
+fn test_encode_pg_timestamp_infinity() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&PgTimestamp::<NaiveDateTime>::PosInfinity, &mut buf);
+    assert_eq!(buf, i64::MAX.to_be_bytes());
+    buf.clear();
+
+    Encode::<Postgres>::encode(&PgTimestamp::<NaiveDateTime>::NegInfinity, &mut buf);
+    assert_eq!(buf, i64::MIN.to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+fn test_decode_pg_timestamp_infinity() {
+    let buf = i64::MAX.to_be_bytes();
+    let value: PgTimestamp<NaiveDateTime> = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(value, PgTimestamp::PosInfinity);
+
+    let buf = i64::MIN.to_be_bytes();
+    let value: PgTimestamp<NaiveDateTime> = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(value, PgTimestamp::NegInfinity);
+}
+
+#[test]
+fn test_encode_pg_date_infinity() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&PgDate::<NaiveDate>::PosInfinity, &mut buf);
+    assert_eq!(buf, i32::MAX.to_be_bytes());
+    buf.clear();
+
+    Encode::<Postgres>::encode(&PgDate::<NaiveDate>::NegInfinity, &mut buf);
+    assert_eq!(buf, i32::MIN.to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+fn test_decode_pg_date_infinity() {
+    let buf = i32::MAX.to_be_bytes();
+    let value: PgDate<NaiveDate> = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(value, PgDate::PosInfinity);
+
+    let buf = i32::MIN.to_be_bytes();
+    let value: PgDate<NaiveDate> = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(value, PgDate::NegInfinity);
+}
+
 #[test]
 fn test_encode_datetime() {
     let mut buf = Vec::new();
@@ -205,4 +566,165 @@ fn test_decode_date() {
     let buf = 7284i32.to_be_bytes();
     let date: NaiveDate = Decode::<Postgres>::decode(Some(&buf));
     assert_eq!(date.to_string(), "2019-12-11");
+}
+
+// `integer_datetimes` is thread-local state, so these exercise the float/microsecond
+// conversion functions directly rather than flipping the flag: `cargo test` runs each
+// test fn on its own thread by default, and there's no guarantee this test's thread
+// isn't reused for another test afterwards, so leaving the flag toggled (even
+// correctly paired set/reset) risks bleeding into unrelated tests.
+#[test]
+fn test_float_seconds_round_trip() {
+    // whole seconds
+    assert_eq!(micros_to_float_seconds(3_600_000_000), 3_600.0);
+    assert_eq!(float_seconds_to_micros(3_600.0), 3_600_000_000);
+
+    // fractional seconds, matching Postgres' float8 wire format for timestamps
+    assert_eq!(micros_to_float_seconds(629_377_265_250_000), 629_377_265.25);
+    assert_eq!(float_seconds_to_micros(629_377_265.25), 629_377_265_250_000);
+
+    // round trips through both directions
+    for micros in &[0i64, 1, -1, 3_600_000_000, -3_600_000_000, 629_377_265_250_000] {
+        assert_eq!(float_seconds_to_micros(micros_to_float_seconds(*micros)), *micros);
+    }
+}
+
+#[test]
+fn test_encode_timetz() {
+    let mut buf = Vec::new();
+
+    // 01:00:00+05:00
+    let value = PgTimeTz { time: NaiveTime::from_hms(1, 0, 0), offset: FixedOffset::east(5 * 3_600) };
+    Encode::<Postgres>::encode(&value, &mut buf);
+
+    assert_eq!(buf[0..8], 3_600_000_000i64.to_be_bytes());
+    assert_eq!(buf[8..12], (-5 * 3_600i32).to_be_bytes());
+}
+
+#[test]
+fn test_decode_timetz() {
+    let mut buf = Vec::new();
+    buf.extend(&3_600_000_000i64.to_be_bytes());
+    buf.extend(&(-5 * 3_600i32).to_be_bytes());
+
+    let value: PgTimeTz = Decode::<Postgres>::decode(Some(&buf));
+
+    assert_eq!(value.time, NaiveTime::from_hms(1, 0, 0));
+    assert_eq!(value.offset, FixedOffset::east(5 * 3_600));
+}
+
+/// Round-trip property tests, in the spirit of diesel's `test_type_round_trips`
+/// harness: instead of a handful of hand-picked constants, generate values across the
+/// representable range and assert `decode(encode(v)) == v`. This is what would have
+/// caught wire-format regressions and the silent truncation the `unwrap_or_else(||
+/// panic!(...))` sites above guard against.
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+    use rand::Rng;
+
+    // chrono's `NaiveDate` range (roughly +/-262,000 years, `NaiveDate::MIN`..=`MAX`) is
+    // itself narrower than the Postgres `date`/`timestamp` wire format's `i32` days, so
+    // it's the binding constraint here. Generate across that full range, minus a
+    // one-day margin so `ArbNaiveDateTime` can add up to `MICROS_PER_DAY - 1` of
+    // same-day time-of-day without overflowing past `NaiveDate::MIN`/`MAX`.
+    const MIN_DAYS: i64 = -96_476_615;
+    const MAX_DAYS: i64 = 95_015_644;
+    const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+    #[derive(Clone, Debug)]
+    struct ArbNaiveTime(NaiveTime);
+
+    impl Arbitrary for ArbNaiveTime {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            let micros = g.gen_range(0, MICROS_PER_DAY);
+            ArbNaiveTime(NaiveTime::from_hms(0, 0, 0) + Duration::microseconds(micros))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ArbNaiveDate(NaiveDate);
+
+    impl Arbitrary for ArbNaiveDate {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            let days = g.gen_range(MIN_DAYS, MAX_DAYS);
+            ArbNaiveDate(NaiveDate::from_ymd(2000, 1, 1) + Duration::days(days))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    struct ArbNaiveDateTime(NaiveDateTime);
+
+    impl Arbitrary for ArbNaiveDateTime {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            let days = g.gen_range(MIN_DAYS, MAX_DAYS);
+            let micros = g.gen_range(0, MICROS_PER_DAY);
+            ArbNaiveDateTime(
+                postgres_epoch().naive_utc()
+                    + Duration::days(days)
+                    + Duration::microseconds(micros),
+            )
+        }
+    }
+
+    quickcheck! {
+        fn time_round_trips(value: ArbNaiveTime) -> bool {
+            let mut buf = Vec::new();
+            Encode::<Postgres>::encode(&value.0, &mut buf);
+            let decoded: NaiveTime = Decode::<Postgres>::decode(Some(&buf));
+            decoded == value.0
+        }
+
+        fn date_round_trips(value: ArbNaiveDate) -> bool {
+            let mut buf = Vec::new();
+            Encode::<Postgres>::encode(&value.0, &mut buf);
+            let decoded: NaiveDate = Decode::<Postgres>::decode(Some(&buf));
+            decoded == value.0
+        }
+
+        fn datetime_round_trips(value: ArbNaiveDateTime) -> bool {
+            let mut buf = Vec::new();
+            Encode::<Postgres>::encode(&value.0, &mut buf);
+            let decoded: NaiveDateTime = Decode::<Postgres>::decode(Some(&buf));
+            decoded == value.0
+        }
+
+        fn datetime_utc_round_trips(value: ArbNaiveDateTime) -> bool {
+            let expected = DateTime::<Utc>::from_utc(value.0, Utc);
+            let mut buf = Vec::new();
+            Encode::<Postgres>::encode(&expected, &mut buf);
+            let decoded: DateTime<Utc> = Decode::<Postgres>::decode(Some(&buf));
+            decoded == expected
+        }
+    }
+
+    #[test]
+    fn test_round_trip_epoch() {
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&postgres_epoch().naive_utc(), &mut buf);
+        let decoded: NaiveDateTime = Decode::<Postgres>::decode(Some(&buf));
+        assert_eq!(decoded, postgres_epoch().naive_utc());
+    }
+
+    #[test]
+    fn test_round_trip_leap_day() {
+        // 2000 was a leap year, and the year the Postgres epoch falls within
+        let leap_day = NaiveDate::from_ymd(2000, 2, 29);
+        let mut buf = Vec::new();
+        Encode::<Postgres>::encode(&leap_day, &mut buf);
+        let decoded: NaiveDate = Decode::<Postgres>::decode(Some(&buf));
+        assert_eq!(decoded, leap_day);
+    }
+
+    #[test]
+    fn test_round_trip_microsecond_edges() {
+        for micros in &[0i64, 1, 999_999, MICROS_PER_DAY - 1] {
+            let time = NaiveTime::from_hms(0, 0, 0) + Duration::microseconds(*micros);
+            let mut buf = Vec::new();
+            Encode::<Postgres>::encode(&time, &mut buf);
+            let decoded: NaiveTime = Decode::<Postgres>::decode(Some(&buf));
+            assert_eq!(decoded, time);
+        }
+    }
 }
\ No newline at end of file
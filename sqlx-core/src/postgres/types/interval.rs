@@ -0,0 +1,101 @@
+use crate::{Decode, Postgres, Encode, HasSqlType, HasTypeMetadata};
+use chrono::Duration;
+use crate::postgres::types::chrono::{decode_micros, encode_micros};
+use crate::postgres::types::{PostgresTypeMetadata, PostgresTypeFormat};
+use crate::encode::IsNull;
+
+use std::convert::{TryFrom, TryInto};
+
+use std::mem::size_of;
+
+postgres_metadata!(
+    // interval
+    PgInterval: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1186,
+        array_oid: 1187
+    },
+);
+
+fn decode<T: Decode<Postgres>>(raw: Option<&[u8]>) -> T {
+    Decode::<Postgres>::decode(raw)
+}
+
+/// A Postgres `interval` value.
+///
+/// Postgres intervals are calendar-relative: `months` and `days` do not have a fixed
+/// duration (a month may be 28-31 days, a day may not be 24 hours across a DST
+/// transition), so they are kept separate from the fixed-length `microseconds`
+/// component rather than folded into a single `chrono::Duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PgInterval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+/// Converts a `chrono::Duration` to a `PgInterval`, representing it in `microseconds`
+/// and leaving `months`/`days` at zero (a `Duration` has no notion of calendar months
+/// or days, so there's nothing to split out). Any sub-microsecond part of the
+/// `Duration` is truncated; fails if the `Duration` overflows `i64` microseconds.
+impl TryFrom<Duration> for PgInterval {
+    type Error = ();
+
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        Ok(PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: value.num_microseconds().ok_or(())?,
+        })
+    }
+}
+
+impl Decode<Postgres> for PgInterval {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let buf = raw.expect("Postgres interval cannot be decoded from NULL");
+
+        let microseconds = decode_micros(Some(&buf[0..8]));
+        let days = i32::from_be_bytes(buf[8..12].try_into().unwrap());
+        let months = i32::from_be_bytes(buf[12..16].try_into().unwrap());
+
+        PgInterval { months, days, microseconds }
+    }
+}
+
+impl Encode<Postgres> for PgInterval {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        encode_micros(self.microseconds, buf);
+        buf.extend(&self.days.to_be_bytes());
+        buf.extend(&self.months.to_be_bytes());
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>() + size_of::<i32>() + size_of::<i32>()
+    }
+}
+
+#[test]
+fn test_encode_interval() {
+    let mut buf = Vec::new();
+
+    let interval = PgInterval { months: 1, days: 2, microseconds: 3_600_000_000 };
+    Encode::<Postgres>::encode(&interval, &mut buf);
+
+    assert_eq!(buf[0..8], 3_600_000_000i64.to_be_bytes());
+    assert_eq!(buf[8..12], 2i32.to_be_bytes());
+    assert_eq!(buf[12..16], 1i32.to_be_bytes());
+}
+
+#[test]
+fn test_decode_interval() {
+    let mut buf = Vec::new();
+    buf.extend(&3_600_000_000i64.to_be_bytes());
+    buf.extend(&2i32.to_be_bytes());
+    buf.extend(&1i32.to_be_bytes());
+
+    let interval: PgInterval = decode(Some(&buf));
+
+    assert_eq!(interval, PgInterval { months: 1, days: 2, microseconds: 3_600_000_000 });
+}
@@ -0,0 +1,208 @@
+use crate::{Decode, Postgres, Encode, HasSqlType, HasTypeMetadata};
+use chrono::{NaiveDate, NaiveDateTime, DateTime, Utc};
+use crate::postgres::types::{PostgresTypeMetadata, PostgresTypeFormat};
+use crate::encode::IsNull;
+
+use std::convert::TryInto;
+use std::mem::size_of;
+use std::ops::Bound;
+
+postgres_metadata!(
+    // daterange
+    PgRange<NaiveDate>: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 3912,
+        array_oid: 3913
+    },
+    // tsrange
+    PgRange<NaiveDateTime>: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 3908,
+        array_oid: 3909
+    },
+    // tstzrange
+    PgRange<DateTime<Utc>>: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 3910,
+        array_oid: 3911
+    },
+);
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LOWER_INCLUSIVE: u8 = 0x02;
+const RANGE_UPPER_INCLUSIVE: u8 = 0x04;
+const RANGE_LOWER_UNBOUNDED: u8 = 0x08;
+const RANGE_UPPER_UNBOUNDED: u8 = 0x10;
+
+/// A Postgres range type (`daterange`, `tsrange`, `tstzrange`), generic over the
+/// element type it ranges over.
+///
+/// Modeled on `std::ops::Bound` for each end rather than a plain `start`/`end` pair so
+/// that inclusive, exclusive, and unbounded ends round-trip exactly as Postgres sent
+/// them; `Empty` is kept as a distinct variant since an empty range carries no bounds
+/// at all on the wire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgRange<T> {
+    Empty,
+    Range { start: Bound<T>, end: Bound<T> },
+}
+
+impl<T> Decode<Postgres> for PgRange<T>
+where
+    T: Decode<Postgres>,
+{
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let buf = raw.expect("Postgres range cannot be decoded from NULL");
+        let flags = buf[0];
+
+        if flags & RANGE_EMPTY != 0 {
+            return PgRange::Empty;
+        }
+
+        let mut rest = &buf[1..];
+
+        let start = if flags & RANGE_LOWER_UNBOUNDED != 0 {
+            Bound::Unbounded
+        } else {
+            let (value, remainder) = decode_bound(rest);
+            rest = remainder;
+
+            if flags & RANGE_LOWER_INCLUSIVE != 0 {
+                Bound::Included(value)
+            } else {
+                Bound::Excluded(value)
+            }
+        };
+
+        let end = if flags & RANGE_UPPER_UNBOUNDED != 0 {
+            Bound::Unbounded
+        } else {
+            let (value, _) = decode_bound(rest);
+
+            if flags & RANGE_UPPER_INCLUSIVE != 0 {
+                Bound::Included(value)
+            } else {
+                Bound::Excluded(value)
+            }
+        };
+
+        PgRange::Range { start, end }
+    }
+}
+
+fn decode_bound<T: Decode<Postgres>>(buf: &[u8]) -> (T, &[u8]) {
+    let len = i32::from_be_bytes(buf[0..4].try_into().unwrap()) as usize;
+    let value = Decode::<Postgres>::decode(Some(&buf[4..4 + len]));
+
+    (value, &buf[4 + len..])
+}
+
+impl<T> Encode<Postgres> for PgRange<T>
+where
+    T: Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let flags_pos = buf.len();
+        buf.push(0);
+
+        let mut flags = 0u8;
+
+        if let PgRange::Range { start, end } = self {
+            match start {
+                Bound::Unbounded => flags |= RANGE_LOWER_UNBOUNDED,
+                Bound::Included(value) => {
+                    flags |= RANGE_LOWER_INCLUSIVE;
+                    encode_bound(value, buf);
+                }
+                Bound::Excluded(value) => encode_bound(value, buf),
+            }
+
+            match end {
+                Bound::Unbounded => flags |= RANGE_UPPER_UNBOUNDED,
+                Bound::Included(value) => {
+                    flags |= RANGE_UPPER_INCLUSIVE;
+                    encode_bound(value, buf);
+                }
+                Bound::Excluded(value) => encode_bound(value, buf),
+            }
+        } else {
+            flags |= RANGE_EMPTY;
+        }
+
+        buf[flags_pos] = flags;
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<u8>()
+    }
+}
+
+fn encode_bound<T: Encode<Postgres>>(value: &T, buf: &mut Vec<u8>) {
+    let len_pos = buf.len();
+    buf.extend(&[0u8; 4]);
+
+    Encode::<Postgres>::encode(value, buf);
+
+    let len = (buf.len() - len_pos - 4) as i32;
+    buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+}
+
+#[test]
+fn test_encode_daterange() {
+    let mut buf = Vec::new();
+
+    let range = PgRange::Range {
+        start: Bound::Included(NaiveDate::from_ymd(2000, 1, 1)),
+        end: Bound::Excluded(NaiveDate::from_ymd(2000, 1, 2)),
+    };
+    Encode::<Postgres>::encode(&range, &mut buf);
+
+    assert_eq!(buf[0], RANGE_LOWER_INCLUSIVE);
+    assert_eq!(buf[1..5], 4i32.to_be_bytes());
+    assert_eq!(buf[5..9], 0i32.to_be_bytes());
+    assert_eq!(buf[9..13], 4i32.to_be_bytes());
+    assert_eq!(buf[13..17], 1i32.to_be_bytes());
+}
+
+#[test]
+fn test_decode_daterange() {
+    let mut buf = Vec::new();
+    buf.push(RANGE_LOWER_INCLUSIVE);
+    buf.extend(&4i32.to_be_bytes());
+    buf.extend(&0i32.to_be_bytes());
+    buf.extend(&4i32.to_be_bytes());
+    buf.extend(&1i32.to_be_bytes());
+
+    let range: PgRange<NaiveDate> = Decode::<Postgres>::decode(Some(&buf));
+
+    assert_eq!(
+        range,
+        PgRange::Range {
+            start: Bound::Included(NaiveDate::from_ymd(2000, 1, 1)),
+            end: Bound::Excluded(NaiveDate::from_ymd(2000, 1, 2)),
+        }
+    );
+}
+
+#[test]
+fn test_encode_decode_empty_range() {
+    let mut buf = Vec::new();
+    Encode::<Postgres>::encode(&PgRange::<NaiveDate>::Empty, &mut buf);
+    assert_eq!(buf, [RANGE_EMPTY]);
+
+    let range: PgRange<NaiveDate> = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(range, PgRange::Empty);
+}
+
+#[test]
+fn test_encode_decode_unbounded_range() {
+    let mut buf = Vec::new();
+    let range = PgRange::<NaiveDate>::Range { start: Bound::Unbounded, end: Bound::Unbounded };
+    Encode::<Postgres>::encode(&range, &mut buf);
+    assert_eq!(buf, [RANGE_LOWER_UNBOUNDED | RANGE_UPPER_UNBOUNDED]);
+
+    let decoded: PgRange<NaiveDate> = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(decoded, range);
+}
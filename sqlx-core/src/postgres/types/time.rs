@@ -0,0 +1,236 @@
+#![cfg(feature = "time")]
+
+use crate::{Decode, Postgres, Encode, HasSqlType, HasTypeMetadata};
+use time::{Time, Date, PrimitiveDateTime, OffsetDateTime, UtcOffset};
+use crate::postgres::types::{PostgresTypeMetadata, PostgresTypeFormat};
+use crate::postgres::types::chrono::{decode_micros, encode_micros};
+use crate::encode::IsNull;
+
+use std::convert::TryInto;
+
+use std::mem::size_of;
+
+postgres_metadata!(
+    // time
+    Time: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1083,
+        array_oid: 1183
+    },
+    // date
+    Date: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1082,
+        array_oid: 1182
+    },
+    // timestamp
+    PrimitiveDateTime: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1114,
+        array_oid: 1115
+    },
+    // timestamptz
+    OffsetDateTime: PostgresTypeMetadata {
+        format: PostgresTypeFormat::Binary,
+        oid: 1184,
+        array_oid: 1185
+    },
+);
+
+fn decode<T: Decode<Postgres>>(raw: Option<&[u8]>) -> T {
+    Decode::<Postgres>::decode(raw)
+}
+
+fn postgres_epoch() -> PrimitiveDateTime {
+    PrimitiveDateTime::new(Date::try_from_ymd(2000, 1, 1).unwrap(), Time::try_from_hms(0, 0, 0).unwrap())
+}
+
+impl Decode<Postgres> for Time {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let micros = decode_micros(raw);
+        Time::try_from_hms_micro(0, 0, 0, 0).unwrap() + time::Duration::microseconds(micros)
+    }
+}
+
+impl Encode<Postgres> for Time {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let micros = (self.hour() as i64 * 3_600_000_000)
+            + (self.minute() as i64 * 60_000_000)
+            + (self.second() as i64 * 1_000_000)
+            + (self.microsecond() as i64);
+
+        encode_micros(micros, buf);
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>()
+    }
+}
+
+impl Decode<Postgres> for Date {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let days: i32 = decode(raw);
+        Date::try_from_ymd(2000, 1, 1).unwrap() + time::Duration::days(days as i64)
+    }
+}
+
+impl Encode<Postgres> for Date {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let days: i32 = (*self - Date::try_from_ymd(2000, 1, 1).unwrap())
+            .whole_days()
+            .try_into()
+            .unwrap_or_else(|_| panic!("Date out of range for Postgres: {:?}", self));
+
+        Encode::<Postgres>::encode(&days, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i32>()
+    }
+}
+
+impl Decode<Postgres> for PrimitiveDateTime {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let micros = decode_micros(raw);
+        postgres_epoch() + time::Duration::microseconds(micros)
+    }
+}
+
+impl Encode<Postgres> for PrimitiveDateTime {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let micros: i64 = (*self - postgres_epoch())
+            .whole_microseconds()
+            .try_into()
+            .unwrap_or_else(|_| panic!("PrimitiveDateTime out of range for Postgres: {:?}", self));
+
+        encode_micros(micros, buf);
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>()
+    }
+}
+
+impl Decode<Postgres> for OffsetDateTime {
+    fn decode(raw: Option<&[u8]>) -> Self {
+        let date_time = <PrimitiveDateTime as Decode<Postgres>>::decode(raw);
+        date_time.assume_utc()
+    }
+}
+
+impl Encode<Postgres> for OffsetDateTime {
+    fn encode(&self, buf: &mut Vec<u8>) -> IsNull {
+        let utc = self.to_offset(UtcOffset::UTC);
+        Encode::<Postgres>::encode(&PrimitiveDateTime::new(utc.date(), utc.time()), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>()
+    }
+}
+
+#[test]
+fn test_encode_time() {
+    let mut buf = Vec::new();
+
+    let time = Time::try_from_hms(0, 0, 0).unwrap();
+    Encode::<Postgres>::encode(&time, &mut buf);
+    assert_eq!(buf, [0; 8]);
+    buf.clear();
+
+    let time2 = Time::try_from_hms(1, 0, 0).unwrap();
+    Encode::<Postgres>::encode(&time2, &mut buf);
+    assert_eq!(buf, 3_600_000_000i64.to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+fn test_decode_time() {
+    let buf = [0u8; 8];
+    let time: Time = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(time, Time::try_from_hms(0, 0, 0).unwrap());
+
+    let buf = 3_600_000_000i64.to_be_bytes();
+    let time: Time = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(time, Time::try_from_hms(1, 0, 0).unwrap());
+}
+
+#[test]
+fn test_encode_date() {
+    let mut buf = Vec::new();
+
+    let date = Date::try_from_ymd(2000, 1, 1).unwrap();
+    Encode::<Postgres>::encode(&date, &mut buf);
+    assert_eq!(buf, [0; 4]);
+    buf.clear();
+
+    let date2 = Date::try_from_ymd(2001, 1, 1).unwrap();
+    Encode::<Postgres>::encode(&date2, &mut buf);
+    // 2000 was a leap year
+    assert_eq!(buf, 366i32.to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+fn test_decode_date() {
+    let buf = [0; 4];
+    let date: Date = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(date, Date::try_from_ymd(2000, 1, 1).unwrap());
+
+    let buf = 366i32.to_be_bytes();
+    let date: Date = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(date, Date::try_from_ymd(2001, 1, 1).unwrap());
+}
+
+#[test]
+fn test_encode_primitive_date_time() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&postgres_epoch(), &mut buf);
+    assert_eq!(buf, [0; 8]);
+    buf.clear();
+
+    let date_time = postgres_epoch() + time::Duration::hours(1);
+    Encode::<Postgres>::encode(&date_time, &mut buf);
+    assert_eq!(buf, 3_600_000_000i64.to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+fn test_decode_primitive_date_time() {
+    let buf = [0u8; 8];
+    let date_time: PrimitiveDateTime = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(date_time, postgres_epoch());
+
+    let buf = 3_600_000_000i64.to_be_bytes();
+    let date_time: PrimitiveDateTime = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(date_time, postgres_epoch() + time::Duration::hours(1));
+}
+
+#[test]
+fn test_encode_offset_date_time() {
+    let mut buf = Vec::new();
+
+    let date_time = postgres_epoch().assume_utc();
+    Encode::<Postgres>::encode(&date_time, &mut buf);
+    assert_eq!(buf, [0; 8]);
+    buf.clear();
+
+    let date_time2 = (postgres_epoch() + time::Duration::hours(1)).assume_utc();
+    Encode::<Postgres>::encode(&date_time2, &mut buf);
+    assert_eq!(buf, 3_600_000_000i64.to_be_bytes());
+    buf.clear();
+}
+
+#[test]
+fn test_decode_offset_date_time() {
+    let buf = [0u8; 8];
+    let date_time: OffsetDateTime = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(date_time, postgres_epoch().assume_utc());
+
+    let buf = 3_600_000_000i64.to_be_bytes();
+    let date_time: OffsetDateTime = Decode::<Postgres>::decode(Some(&buf));
+    assert_eq!(date_time, (postgres_epoch() + time::Duration::hours(1)).assume_utc());
+}